@@ -0,0 +1,16 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EventRegistryError {
+    EventAlreadyExists = 1,
+    EventNotFound = 2,
+    EventInactive = 3,
+    InvalidFeePercent = 4,
+    NotInitialized = 5,
+    InvitationRequired = 6,
+    EventNotExpired = 7,
+    NoPendingAdmin = 8,
+    NotAuthorized = 9,
+}
@@ -0,0 +1,55 @@
+use soroban_sdk::{contractevent, Address, String};
+
+/// Emitted when a new event is registered.
+#[contractevent]
+pub struct EventRegistered {
+    pub event_id: String,
+    pub organizer_address: Address,
+    pub payment_address: Address,
+    pub timestamp: u64,
+}
+
+/// Emitted when an organizer flips an event's active status.
+#[contractevent]
+pub struct EventStatusUpdated {
+    pub event_id: String,
+    pub is_active: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Emitted when the platform-wide fee changes, or a single event's fee
+/// is overridden (in which case `event_id` is set).
+#[contractevent]
+pub struct FeeUpdated {
+    pub new_fee_percent: u32,
+    pub event_id: Option<String>,
+}
+
+/// Emitted when an organizer adds an attendee to an event's allowlist.
+#[contractevent]
+pub struct InvitationAdded {
+    pub event_id: String,
+    pub attendee: Address,
+}
+
+/// Emitted when an organizer removes an attendee from an event's allowlist.
+#[contractevent]
+pub struct InvitationRevoked {
+    pub event_id: String,
+    pub attendee: Address,
+}
+
+/// Emitted when an expired event is reaped and tombstoned.
+#[contractevent]
+pub struct EventEvicted {
+    pub event_id: String,
+    pub timestamp: u64,
+}
+
+/// Emitted when a proposed admin accepts the role, finalizing the handover.
+#[contractevent]
+pub struct AdminChanged {
+    pub old: Address,
+    pub new: Address,
+}
@@ -0,0 +1,95 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::storage::{EVENT_BUMP_AMOUNT, EVENT_LIFETIME_THRESHOLD};
+
+/// Storage keys for the invitation allowlist. `Invitee` is keyed by
+/// `(event_id, Address)` so membership checks — run on every gated
+/// `get_event_payment_info` call — are a single O(1) lookup instead of a
+/// linear scan. `Invitees` is a secondary index kept only to serve
+/// `list_invitations`.
+#[derive(Clone)]
+#[contracttype]
+enum InvitationKey {
+    Invitee(String, Address),
+    Invitees(String),
+}
+
+/// Adds `attendee` to `event_id`'s allowlist. Returns whether the
+/// allowlist actually changed, so callers can avoid publishing an event
+/// for a no-op (attendee already invited).
+pub fn add_invitation(env: &Env, event_id: String, attendee: Address) -> bool {
+    let invitee_key = InvitationKey::Invitee(event_id.clone(), attendee.clone());
+    if env.storage().persistent().has(&invitee_key) {
+        env.storage().persistent().extend_ttl(
+            &invitee_key,
+            EVENT_LIFETIME_THRESHOLD,
+            EVENT_BUMP_AMOUNT,
+        );
+        return false;
+    }
+    env.storage().persistent().set(&invitee_key, &true);
+    env.storage().persistent().extend_ttl(
+        &invitee_key,
+        EVENT_LIFETIME_THRESHOLD,
+        EVENT_BUMP_AMOUNT,
+    );
+
+    let list_key = InvitationKey::Invitees(event_id);
+    let mut invitees = env
+        .storage()
+        .persistent()
+        .get(&list_key)
+        .unwrap_or(Vec::new(env));
+    invitees.push_back(attendee);
+    env.storage().persistent().set(&list_key, &invitees);
+    env.storage()
+        .persistent()
+        .extend_ttl(&list_key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+    true
+}
+
+/// Removes `attendee` from `event_id`'s allowlist. Returns whether the
+/// allowlist actually changed, so callers can avoid publishing an event
+/// for a no-op (attendee wasn't invited).
+pub fn revoke_invitation(env: &Env, event_id: String, attendee: Address) -> bool {
+    let invitee_key = InvitationKey::Invitee(event_id.clone(), attendee.clone());
+    if !env.storage().persistent().has(&invitee_key) {
+        return false;
+    }
+    env.storage().persistent().remove(&invitee_key);
+
+    let list_key = InvitationKey::Invitees(event_id);
+    let mut invitees: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&list_key)
+        .unwrap_or(Vec::new(env));
+    if let Some(index) = invitees.iter().position(|a| a == attendee) {
+        invitees.remove(index as u32);
+        env.storage().persistent().set(&list_key, &invitees);
+        env.storage().persistent().extend_ttl(
+            &list_key,
+            EVENT_LIFETIME_THRESHOLD,
+            EVENT_BUMP_AMOUNT,
+        );
+    }
+    true
+}
+
+pub fn list_invitations(env: &Env, event_id: String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&InvitationKey::Invitees(event_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn is_invited(env: &Env, event_id: String, attendee: &Address) -> bool {
+    let key = InvitationKey::Invitee(event_id, attendee.clone());
+    let invited = env.storage().persistent().has(&key);
+    if invited {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+    }
+    invited
+}
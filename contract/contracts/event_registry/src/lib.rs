@@ -1,11 +1,15 @@
 #![no_std]
 
-use crate::events::{EventRegistered, EventStatusUpdated, FeeUpdated};
-use crate::types::{EventInfo, PaymentInfo};
+use crate::events::{
+    AdminChanged, EventEvicted, EventRegistered, EventStatusUpdated, FeeUpdated, InvitationAdded,
+    InvitationRevoked,
+};
+use crate::types::{EventInfo, EventRecord, FeeChange, PaymentInfo};
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
 pub mod error;
 pub mod events;
+pub mod invitations;
 pub mod storage;
 pub mod types;
 
@@ -39,6 +43,8 @@ impl EventRegistry {
         event_id: String,
         organizer_address: Address,
         payment_address: Address,
+        invitation_required: bool,
+        ttl: u64,
     ) -> Result<(), EventRegistryError> {
         // Verify organizer signature
         organizer_address.require_auth();
@@ -51,6 +57,13 @@ impl EventRegistry {
         // Get current platform fee
         let platform_fee_percent = storage::get_platform_fee(&env);
 
+        // A `ttl` of zero means the event never expires on its own.
+        let expiry_ledger = if ttl > 0 {
+            Some(env.ledger().timestamp() + ttl)
+        } else {
+            None
+        };
+
         // Create event info with current timestamp
         let event_info = EventInfo {
             event_id: event_id.clone(),
@@ -59,11 +72,22 @@ impl EventRegistry {
             platform_fee_percent,
             is_active: true,
             created_at: env.ledger().timestamp(),
+            invitation_required,
+            expiry_ledger,
         };
 
         // Store the event
         storage::store_event(&env, event_info);
 
+        // Record the event's initial fee as the first entry in its
+        // audit trail.
+        storage::record_fee_change(
+            &env,
+            event_id.clone(),
+            platform_fee_percent,
+            env.ledger().timestamp(),
+        );
+
         // Emit registration event using contract event type
         EventRegistered {
             event_id: event_id.clone(),
@@ -76,33 +100,88 @@ impl EventRegistry {
         Ok(())
     }
 
-    /// Get event payment information
+    /// Get event payment information. When the event gates entry behind an
+    /// invitation, `caller` must be on the allowlist.
     pub fn get_event_payment_info(
         env: Env,
         event_id: String,
+        caller: Address,
     ) -> Result<PaymentInfo, EventRegistryError> {
-        match storage::get_event(&env, event_id) {
-            Some(event_info) => {
+        match storage::get_event_record(&env, event_id.clone()) {
+            Some(EventRecord::Active(event_info)) => {
                 if !event_info.is_active {
                     return Err(EventRegistryError::EventInactive);
                 }
+                if event_info.invitation_required {
+                    // Bind the `caller` claim to the actual signer so an
+                    // invited address can't be passed in by anyone else.
+                    caller.require_auth();
+                    if !invitations::is_invited(&env, event_id, &caller) {
+                        return Err(EventRegistryError::InvitationRequired);
+                    }
+                }
                 Ok(PaymentInfo {
                     payment_address: event_info.payment_address,
                     platform_fee_percent: event_info.platform_fee_percent,
                 })
             }
+            Some(EventRecord::Evicted(_)) => Err(EventRegistryError::EventInactive),
             None => Err(EventRegistryError::EventNotFound),
         }
     }
 
+    /// Adds an attendee to an event's invitation allowlist. Only the
+    /// event's organizer may invite attendees.
+    pub fn add_invitation(
+        env: Env,
+        event_id: String,
+        attendee: Address,
+    ) -> Result<(), EventRegistryError> {
+        let event_info = get_active_event(&env, event_id.clone())?;
+        event_info.organizer_address.require_auth();
+
+        if invitations::add_invitation(&env, event_id.clone(), attendee.clone()) {
+            InvitationAdded { event_id, attendee }.publish(&env);
+        }
+
+        Ok(())
+    }
+
+    /// Removes an attendee from an event's invitation allowlist. Only the
+    /// event's organizer may revoke invitations.
+    pub fn revoke_invitation(
+        env: Env,
+        event_id: String,
+        attendee: Address,
+    ) -> Result<(), EventRegistryError> {
+        let event_info = get_active_event(&env, event_id.clone())?;
+        event_info.organizer_address.require_auth();
+
+        if invitations::revoke_invitation(&env, event_id.clone(), attendee.clone()) {
+            InvitationRevoked { event_id, attendee }.publish(&env);
+        }
+
+        Ok(())
+    }
+
+    /// Lists every attendee invited to an event.
+    pub fn list_invitations(env: Env, event_id: String) -> Vec<Address> {
+        invitations::list_invitations(&env, event_id)
+    }
+
+    /// Checks whether an attendee is on an event's invitation allowlist.
+    pub fn is_invited(env: Env, event_id: String, attendee: Address) -> bool {
+        invitations::is_invited(&env, event_id, &attendee)
+    }
+
     /// Update event status (only by organizer)
     pub fn update_event_status(
         env: Env,
         event_id: String,
         is_active: bool,
     ) -> Result<(), EventRegistryError> {
-        match storage::get_event(&env, event_id.clone()) {
-            Some(mut event_info) => {
+        match storage::get_event_record(&env, event_id.clone()) {
+            Some(EventRecord::Active(mut event_info)) => {
                 // Verify organizer signature
                 event_info.organizer_address.require_auth();
 
@@ -121,10 +200,46 @@ impl EventRegistry {
 
                 Ok(())
             }
+            // A tombstoned event can't be reactivated: `reap_event`
+            // discards the organizer/payment data this call would need
+            // to re-activate it, so there's nothing to flip back on.
+            Some(EventRecord::Evicted(_)) => Err(EventRegistryError::EventInactive),
             None => Err(EventRegistryError::EventNotFound),
         }
     }
 
+    /// Reaps an expired event, callable by anyone. Rather than deleting
+    /// the record outright, it's replaced with a minimal tombstone (only
+    /// `event_id` + `is_active = false`) so lookups keep reporting
+    /// `EventInactive` and the id can't be silently reused by a new
+    /// registration, without keeping the organizer/payment/fee data of a
+    /// dead event around indefinitely.
+    pub fn reap_event(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let event_info = get_active_event(&env, event_id.clone())?;
+
+        if !event_info.is_active {
+            return Err(EventRegistryError::EventInactive);
+        }
+
+        let expiry_ledger = event_info
+            .expiry_ledger
+            .ok_or(EventRegistryError::EventNotExpired)?;
+        if env.ledger().timestamp() < expiry_ledger {
+            return Err(EventRegistryError::EventNotExpired);
+        }
+
+        storage::tombstone_event(&env, event_id.clone());
+        storage::remove_organizer_event(&env, &event_info.organizer_address, &event_id);
+
+        EventEvicted {
+            event_id,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
     /// Stores or updates an event (legacy function for backward compatibility).
     pub fn store_event(env: Env, event_info: EventInfo) {
         // In a real scenario, we would check authorization here.
@@ -158,11 +273,61 @@ impl EventRegistry {
         storage::set_platform_fee(&env, new_fee_percent);
 
         // Emit fee update event using contract event type
-        FeeUpdated { new_fee_percent }.publish(&env);
+        FeeUpdated {
+            new_fee_percent,
+            event_id: None,
+        }
+        .publish(&env);
 
         Ok(())
     }
 
+    /// Overrides a single event's fee, diverging it from the platform
+    /// default. Callable by the event's organizer or the platform admin.
+    pub fn set_event_fee(
+        env: Env,
+        event_id: String,
+        new_fee_percent: u32,
+        caller: Address,
+    ) -> Result<(), EventRegistryError> {
+        caller.require_auth();
+
+        if new_fee_percent > 10000 {
+            return Err(EventRegistryError::InvalidFeePercent);
+        }
+
+        let mut event_info = get_active_event(&env, event_id.clone())?;
+
+        let is_admin = storage::get_admin(&env).as_ref() == Some(&caller);
+        if caller != event_info.organizer_address && !is_admin {
+            return Err(EventRegistryError::NotAuthorized);
+        }
+
+        if !event_info.is_active {
+            return Err(EventRegistryError::EventInactive);
+        }
+
+        event_info.platform_fee_percent = new_fee_percent;
+        storage::store_event(&env, event_info);
+
+        let timestamp = env.ledger().timestamp();
+        storage::record_fee_change(&env, event_id.clone(), new_fee_percent, timestamp);
+
+        FeeUpdated {
+            new_fee_percent,
+            event_id: Some(event_id),
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the full audit trail of fee changes applied to an event,
+    /// including its initial fee at registration.
+    pub fn get_event_fee_history(env: Env, event_id: String) -> Vec<FeeChange> {
+        storage::get_fee_history(&env, event_id)
+    }
+
     /// Returns the current platform fee percentage.
     pub fn get_platform_fee(env: Env) -> u32 {
         storage::get_platform_fee(&env)
@@ -172,6 +337,69 @@ impl EventRegistry {
     pub fn get_admin(env: Env) -> Result<Address, EventRegistryError> {
         storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)
     }
+
+    /// Proposes a new administrator. The handover only takes effect once
+    /// `new_admin` calls `accept_admin`, so a typo here can't brick
+    /// platform administration.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+
+        storage::set_pending_admin(&env, &new_admin);
+
+        Ok(())
+    }
+
+    /// Finalizes a pending admin handover. Must be called by the proposed
+    /// admin.
+    pub fn accept_admin(env: Env) -> Result<(), EventRegistryError> {
+        let pending_admin =
+            storage::get_pending_admin(&env).ok_or(EventRegistryError::NoPendingAdmin)?;
+        pending_admin.require_auth();
+
+        let old_admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        storage::set_admin(&env, &pending_admin);
+        storage::clear_pending_admin(&env);
+
+        AdminChanged {
+            old: old_admin,
+            new: pending_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Cancels a pending admin proposal.
+    pub fn cancel_admin_proposal(env: Env) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+
+        storage::clear_pending_admin(&env);
+
+        Ok(())
+    }
+
+    /// Proactively extends an event's storage TTL. Only the event's
+    /// organizer may do this.
+    pub fn bump_event_ttl(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let event_info = get_active_event(&env, event_id.clone())?;
+        event_info.organizer_address.require_auth();
+
+        storage::bump_event_ttl(&env, event_id);
+        Ok(())
+    }
+}
+
+/// Fetches a live event record, distinguishing "never existed" from
+/// "exists but was tombstoned by `reap_event`" so callers can return
+/// `EventNotFound` vs. `EventInactive` correctly.
+fn get_active_event(env: &Env, event_id: String) -> Result<EventInfo, EventRegistryError> {
+    match storage::get_event_record(env, event_id) {
+        Some(EventRecord::Active(event_info)) => Ok(event_info),
+        Some(EventRecord::Evicted(_)) => Err(EventRegistryError::EventInactive),
+        None => Err(EventRegistryError::EventNotFound),
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,192 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::types::{EventInfo, EventRecord, EventTombstone, FeeChange};
+
+/// Bump amount applied to event entries every time they're read or
+/// written, mirroring the balance-bump pattern used by Soroban's native
+/// token contract.
+pub const EVENT_BUMP_AMOUNT: u32 = 518400; // ~30 days, assuming 5s ledgers
+pub const EVENT_LIFETIME_THRESHOLD: u32 = EVENT_BUMP_AMOUNT - 17280; // re-extend once under ~29 days remain
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    PendingAdmin,
+    PlatformFee,
+    Event(String),
+    OrganizerEvents(Address),
+    FeeHistory(String),
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+    env.storage()
+        .instance()
+        .extend_ttl(EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn set_pending_admin(env: &Env, pending_admin: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, pending_admin);
+    env.storage()
+        .instance()
+        .extend_ttl(EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+}
+
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+pub fn has_platform_fee(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::PlatformFee)
+}
+
+pub fn get_platform_fee(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PlatformFee)
+        .unwrap_or(0)
+}
+
+pub fn set_platform_fee(env: &Env, platform_fee_percent: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PlatformFee, &platform_fee_percent);
+    env.storage()
+        .instance()
+        .extend_ttl(EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+}
+
+pub fn event_exists(env: &Env, event_id: String) -> bool {
+    env.storage().persistent().has(&DataKey::Event(event_id))
+}
+
+pub fn store_event(env: &Env, event_info: EventInfo) {
+    let key = DataKey::Event(event_info.event_id.clone());
+    let organizer = event_info.organizer_address.clone();
+    let event_id = event_info.event_id.clone();
+    let record = EventRecord::Active(event_info);
+    env.storage().persistent().set(&key, &record);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+    add_organizer_event(env, &organizer, event_id);
+}
+
+/// Replaces a live event with a minimal tombstone, freeing the
+/// organizer/payment/fee data it used to carry. The organizer's index is
+/// pruned separately by the caller, since this function doesn't know the
+/// evicted event's former organizer.
+pub fn tombstone_event(env: &Env, event_id: String) {
+    let key = DataKey::Event(event_id.clone());
+    let record = EventRecord::Evicted(EventTombstone {
+        event_id,
+        is_active: false,
+    });
+    env.storage().persistent().set(&key, &record);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+}
+
+pub fn get_event_record(env: &Env, event_id: String) -> Option<EventRecord> {
+    let key = DataKey::Event(event_id);
+    let record = env.storage().persistent().get(&key);
+    if record.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+    }
+    record
+}
+
+/// Returns the live `EventInfo` for an event, or `None` if it doesn't
+/// exist or has been tombstoned by `reap_event`.
+pub fn get_event(env: &Env, event_id: String) -> Option<EventInfo> {
+    match get_event_record(env, event_id) {
+        Some(EventRecord::Active(event_info)) => Some(event_info),
+        Some(EventRecord::Evicted(_)) | None => None,
+    }
+}
+
+/// Proactively extends the TTL of an event entry, for organizers who
+/// want to keep a dormant event from being archived by the host.
+pub fn bump_event_ttl(env: &Env, event_id: String) {
+    let key = DataKey::Event(event_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+}
+
+pub fn get_organizer_events(env: &Env, organizer: &Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrganizerEvents(organizer.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Prunes a single event id out of an organizer's index, e.g. once that
+/// event has been reaped.
+pub fn remove_organizer_event(env: &Env, organizer: &Address, event_id: &String) {
+    let key = DataKey::OrganizerEvents(organizer.clone());
+    let mut events = get_organizer_events(env, organizer);
+    if let Some(index) = events.iter().position(|id| &id == event_id) {
+        events.remove(index as u32);
+        env.storage().persistent().set(&key, &events);
+    }
+}
+
+pub fn get_fee_history(env: &Env, event_id: String) -> Vec<FeeChange> {
+    let key = DataKey::FeeHistory(event_id);
+    if env.storage().persistent().has(&key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+    }
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends a fee snapshot to an event's audit trail. Called both when an
+/// event is registered (recording its initial fee) and whenever that
+/// fee is later overridden.
+pub fn record_fee_change(env: &Env, event_id: String, fee_percent: u32, timestamp: u64) {
+    let key = DataKey::FeeHistory(event_id);
+    let mut history: Vec<FeeChange> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    history.push_back(FeeChange {
+        timestamp,
+        fee_percent,
+    });
+    env.storage().persistent().set(&key, &history);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+}
+
+fn add_organizer_event(env: &Env, organizer: &Address, event_id: String) {
+    let key = DataKey::OrganizerEvents(organizer.clone());
+    let mut events = get_organizer_events(env, organizer);
+    if !events.contains(&event_id) {
+        events.push_back(event_id);
+    }
+    env.storage().persistent().set(&key, &events);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, EVENT_LIFETIME_THRESHOLD, EVENT_BUMP_AMOUNT);
+}
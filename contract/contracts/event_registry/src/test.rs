@@ -0,0 +1,183 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::error::EventRegistryError;
+use crate::{EventRegistry, EventRegistryClient};
+
+fn setup(env: &Env) -> (EventRegistryClient, Address, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let organizer = Address::generate(env);
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(env, &contract_id);
+    client.initialize(&admin, &0);
+
+    (client, admin, organizer)
+}
+
+fn register_gated_event(
+    env: &Env,
+    client: &EventRegistryClient,
+    organizer: &Address,
+    event_id: &String,
+) {
+    let payment_address = Address::generate(env);
+    client.register_event(event_id, organizer, &payment_address, &true, &0);
+}
+
+#[test]
+fn gated_payment_info_requires_invitation() {
+    let env = Env::default();
+    let (client, _admin, organizer) = setup(&env);
+    let event_id = String::from_str(&env, "event-1");
+    register_gated_event(&env, &client, &organizer, &event_id);
+
+    let attendee = Address::generate(&env);
+
+    // Not invited yet: the allowlist check should reject the caller.
+    let result = client.try_get_event_payment_info(&event_id, &attendee);
+    assert_eq!(result, Err(Ok(EventRegistryError::InvitationRequired)));
+
+    client.add_invitation(&event_id, &attendee);
+
+    // Invited: the same caller now gets the payment info back.
+    let payment_info = client.get_event_payment_info(&event_id, &attendee);
+    assert_eq!(payment_info.platform_fee_percent, 0);
+}
+
+#[test]
+fn gated_payment_info_binds_caller_to_the_real_signer() {
+    let env = Env::default();
+    let (client, _admin, organizer) = setup(&env);
+    let event_id = String::from_str(&env, "event-1");
+    register_gated_event(&env, &client, &organizer, &event_id);
+
+    let attendee = Address::generate(&env);
+    client.add_invitation(&event_id, &attendee);
+
+    // With `mock_all_auths` every address authorizes, so the happy path
+    // above always succeeds regardless of who's really calling. Switch to
+    // an explicit, empty auth list to prove `caller.require_auth()` is
+    // actually enforced rather than decorative.
+    env.set_auths(&[]);
+    let result = client.try_get_event_payment_info(&event_id, &attendee);
+    assert!(result.is_err());
+}
+
+#[test]
+fn ungated_payment_info_does_not_require_invitation() {
+    let env = Env::default();
+    let (client, _admin, organizer) = setup(&env);
+    let event_id = String::from_str(&env, "event-2");
+    let payment_address = Address::generate(&env);
+    client.register_event(&event_id, &organizer, &payment_address, &false, &0);
+
+    let anyone = Address::generate(&env);
+    let payment_info = client.get_event_payment_info(&event_id, &anyone);
+    assert_eq!(payment_info.payment_address, payment_address);
+}
+
+#[test]
+fn revoked_invitation_is_no_longer_accepted() {
+    let env = Env::default();
+    let (client, _admin, organizer) = setup(&env);
+    let event_id = String::from_str(&env, "event-1");
+    register_gated_event(&env, &client, &organizer, &event_id);
+
+    let attendee = Address::generate(&env);
+    client.add_invitation(&event_id, &attendee);
+    client.revoke_invitation(&event_id, &attendee);
+
+    let result = client.try_get_event_payment_info(&event_id, &attendee);
+    assert_eq!(result, Err(Ok(EventRegistryError::InvitationRequired)));
+}
+
+#[test]
+fn admin_handover_requires_new_admin_to_accept() {
+    let env = Env::default();
+    let (client, admin, _organizer) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin);
+    // The old admin is still in charge until the handover is accepted.
+    assert_eq!(client.get_admin(), admin);
+
+    client.accept_admin();
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+#[test]
+fn admin_proposal_can_be_cancelled() {
+    let env = Env::default();
+    let (client, admin, _organizer) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin);
+    client.cancel_admin_proposal();
+
+    // With the proposal cancelled, there's nothing left to accept.
+    let result = client.try_accept_admin();
+    assert_eq!(result, Err(Ok(EventRegistryError::NoPendingAdmin)));
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn accept_admin_fails_without_a_pending_proposal() {
+    let env = Env::default();
+    let (client, _admin, _organizer) = setup(&env);
+
+    let result = client.try_accept_admin();
+    assert_eq!(result, Err(Ok(EventRegistryError::NoPendingAdmin)));
+}
+
+#[test]
+fn organizer_and_admin_can_override_an_event_fee() {
+    let env = Env::default();
+    let (client, admin, organizer) = setup(&env);
+    let event_id = String::from_str(&env, "event-1");
+    let payment_address = Address::generate(&env);
+    client.register_event(&event_id, &organizer, &payment_address, &false, &0);
+
+    client.set_event_fee(&event_id, &500, &organizer);
+    assert_eq!(
+        client
+            .get_event_payment_info(&event_id, &organizer)
+            .platform_fee_percent,
+        500
+    );
+
+    client.set_event_fee(&event_id, &250, &admin);
+    assert_eq!(
+        client
+            .get_event_payment_info(&event_id, &organizer)
+            .platform_fee_percent,
+        250
+    );
+}
+
+#[test]
+fn stranger_cannot_override_an_event_fee() {
+    let env = Env::default();
+    let (client, _admin, organizer) = setup(&env);
+    let event_id = String::from_str(&env, "event-1");
+    let payment_address = Address::generate(&env);
+    client.register_event(&event_id, &organizer, &payment_address, &false, &0);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_set_event_fee(&event_id, &500, &stranger);
+    assert_eq!(result, Err(Ok(EventRegistryError::NotAuthorized)));
+}
+
+#[test]
+fn fee_override_rejected_on_inactive_event() {
+    let env = Env::default();
+    let (client, _admin, organizer) = setup(&env);
+    let event_id = String::from_str(&env, "event-1");
+    let payment_address = Address::generate(&env);
+    client.register_event(&event_id, &organizer, &payment_address, &false, &0);
+
+    client.update_event_status(&event_id, &false);
+
+    let result = client.try_set_event_fee(&event_id, &500, &organizer);
+    assert_eq!(result, Err(Ok(EventRegistryError::EventInactive)));
+}
@@ -0,0 +1,55 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// Everything the registry knows about a single event.
+#[derive(Clone)]
+#[contracttype]
+pub struct EventInfo {
+    pub event_id: String,
+    pub organizer_address: Address,
+    pub payment_address: Address,
+    pub platform_fee_percent: u32,
+    pub is_active: bool,
+    pub created_at: u64,
+    pub invitation_required: bool,
+    /// Ledger timestamp after which the event becomes eligible for
+    /// `reap_event`. `None` means the event never expires on its own.
+    pub expiry_ledger: Option<u64>,
+}
+
+/// Minimal record left behind by `reap_event`: just enough to answer
+/// "does this id exist" and "why can't I use it" without keeping the
+/// organizer/payment/fee data of an evicted event around indefinitely.
+#[derive(Clone)]
+#[contracttype]
+pub struct EventTombstone {
+    pub event_id: String,
+    pub is_active: bool,
+}
+
+/// What's actually stored per event: a live record, or the tombstone
+/// left behind once it's reaped. Keeping this as an enum (rather than an
+/// `evicted` flag on `EventInfo`) means there's no code path that can
+/// turn a tombstone back into a live record.
+#[derive(Clone)]
+#[contracttype]
+pub enum EventRecord {
+    Active(EventInfo),
+    Evicted(EventTombstone),
+}
+
+/// Read-only view returned to callers who only need to know where and
+/// how much to pay.
+#[derive(Clone)]
+#[contracttype]
+pub struct PaymentInfo {
+    pub payment_address: Address,
+    pub platform_fee_percent: u32,
+}
+
+/// A single entry in an event's append-only fee-change history.
+#[derive(Clone)]
+#[contracttype]
+pub struct FeeChange {
+    pub timestamp: u64,
+    pub fee_percent: u32,
+}